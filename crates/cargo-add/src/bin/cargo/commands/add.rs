@@ -58,7 +58,11 @@ Additionally, you can specify features for a dependency by following it with a `
                 .help("Space-separated list of features to add")
                 .long_help("Space-separated list of features to add
 
-Alternatively, you can specify features for a dependency by following it with a `+<FEATURE>`."),
+Alternatively, you can specify features for a dependency by following it with a `+<FEATURE>`.
+
+Namespaced (`dep:<name>`) and weak (`<pkg>?/<feat>`) feature syntax is supported and passed through as-is.
+
+When adding multiple crates, `--features` applies to the crate named immediately before it, so `cargo add serde -F derive serde_json -F preserve_order` gives each crate its own feature set."),
             clap::Arg::new("optional")
                 .long("optional")
                 .help("Mark the dependency as optional")
@@ -91,6 +95,13 @@ Example uses:
                 .value_name("NAME")
                 .help("Package registry for this dependency")
                 .conflicts_with("git"),
+            clap::Arg::new("path")
+                .long("path")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Filesystem path to local crate to add")
+                .conflicts_with("registry")
+                .conflicts_with("git"),
         ])
         .arg_manifest_path()
         .args([
@@ -205,11 +216,24 @@ pub fn exec(config: &mut Config, args: &ArgMatches) -> CliResult {
         section,
         dry_run,
     };
+    // TODO: feature discovery (printing a crate's available features after
+    // an invalid `+<feature>`/`-F` value) is not implemented. Doing so needs
+    // the crate's resolved `Summary`, which `cargo_add::ops::add` (not part
+    // of this checkout) would have to surface, e.g. as a dedicated error
+    // variant, before this can be wired up.
     add(&ws, &options)?;
 
     Ok(())
 }
 
+/// A `crates` positional value or a `-F/--features` occurrence.
+/// `parse_dependencies` pairs each one with its position on the command line
+/// so the two can be merged back into the order the user actually typed them in.
+enum CrateToken {
+    Crate(String),
+    Features(String),
+}
+
 fn parse_dependencies<'m>(config: &Config, matches: &'m ArgMatches) -> CargoResult<Vec<DepOp>> {
     let crates = matches
         .values_of("crates")
@@ -221,14 +245,10 @@ fn parse_dependencies<'m>(config: &Config, matches: &'m ArgMatches) -> CargoResu
     let branch = matches.value_of("branch");
     let rev = matches.value_of("rev");
     let tag = matches.value_of("tag");
+    let path = matches.value_of("path");
     let rename = matches.value_of("rename");
     let registry = matches.registry(config)?;
     let default_features = default_features(matches);
-    let features = matches.values_of("features").map(|f| {
-        f.flat_map(parse_feature)
-            .map(String::from)
-            .collect::<IndexSet<_>>()
-    });
     let optional = optional(matches);
 
     if crates.len() > 1 && git.is_some() {
@@ -238,44 +258,83 @@ fn parse_dependencies<'m>(config: &Config, matches: &'m ArgMatches) -> CargoResu
         anyhow::bail!("`--git` is unstable and requires `-Z unstable-options`");
     }
 
+    if crates.len() > 1 && path.is_some() {
+        anyhow::bail!("cannot specify multiple crates with path");
+    }
+
     if crates.len() > 1 && rename.is_some() {
         anyhow::bail!("cannot specify multiple crates with rename");
     }
 
-    if crates.len() > 1 && features.is_some() {
-        anyhow::bail!("cannot specify multiple crates with features");
-    }
+    // Merge the `crates` positional and `-F/--features` occurrences back into
+    // the order they appeared on the command line, so each `--features`
+    // binds to the crate named immediately before it, the same way
+    // `+<feature>` already does.
+    let mut tokens = matches
+        .indices_of("crates")
+        .into_iter()
+        .flatten()
+        .zip(crates)
+        .map(|(i, c)| (i, CrateToken::Crate(c)))
+        .collect::<Vec<_>>();
+    tokens.extend(
+        matches
+            .indices_of("features")
+            .into_iter()
+            .flatten()
+            .zip(matches.values_of("features").into_iter().flatten())
+            .map(|(i, f)| (i, CrateToken::Features(f.to_owned()))),
+    );
+    tokens.sort_by_key(|(i, _)| *i);
 
     let mut deps: Vec<DepOp> = Vec::new();
-    for crate_spec in crates {
-        if let Some(features) = crate_spec.strip_prefix('+') {
-            if !config.cli_unstable().unstable_options {
-                anyhow::bail!("`+<feature>` is unstable and requires `-Z unstable-options`");
-            }
+    for (_, token) in tokens {
+        match token {
+            CrateToken::Crate(crate_spec) => {
+                if let Some(features) = crate_spec.strip_prefix('+') {
+                    if !config.cli_unstable().unstable_options {
+                        anyhow::bail!(
+                            "`+<feature>` is unstable and requires `-Z unstable-options`"
+                        );
+                    }
 
-            if let Some(prior) = deps.last_mut() {
-                let features = parse_feature(features);
-                prior
-                    .features
-                    .get_or_insert_with(Default::default)
-                    .extend(features.map(String::from));
-            } else {
-                anyhow::bail!("`+<feature>` must be preceded by a pkgid");
+                    if let Some(prior) = deps.last_mut() {
+                        let features = parse_feature(features);
+                        prior
+                            .features
+                            .get_or_insert_with(Default::default)
+                            .extend(features.map(String::from));
+                    } else {
+                        anyhow::bail!("`+<feature>` must be preceded by a pkgid");
+                    }
+                } else {
+                    let dep = DepOp {
+                        crate_spec,
+                        rename: rename.map(String::from),
+                        features: None,
+                        default_features,
+                        optional,
+                        registry: registry.clone(),
+                        path: path.map(String::from),
+                        git: git.map(String::from),
+                        branch: branch.map(String::from),
+                        rev: rev.map(String::from),
+                        tag: tag.map(String::from),
+                    };
+                    deps.push(dep);
+                }
+            }
+            CrateToken::Features(features) => {
+                let features = parse_feature(&features).map(String::from);
+                if let Some(prior) = deps.last_mut() {
+                    prior
+                        .features
+                        .get_or_insert_with(IndexSet::default)
+                        .extend(features);
+                } else {
+                    anyhow::bail!("`--features` must be preceded by a pkgid");
+                }
             }
-        } else {
-            let dep = DepOp {
-                crate_spec,
-                rename: rename.map(String::from),
-                features: features.clone(),
-                default_features,
-                optional,
-                registry: registry.clone(),
-                git: git.map(String::from),
-                branch: branch.map(String::from),
-                rev: rev.map(String::from),
-                tag: tag.map(String::from),
-            };
-            deps.push(dep);
         }
     }
     Ok(deps)
@@ -324,6 +383,15 @@ fn parse_section(matches: &ArgMatches) -> DepTable {
 }
 
 /// Split feature flag list
+///
+/// Only splits on whitespace and `,`, so namespaced (`dep:<name>`) and weak
+/// (`<pkg>?/<feat>`) feature syntax is preserved as a single token and passed
+/// through to the manifest unchanged.
+///
+/// NOT IMPLEMENTED: avoiding a redundant implicit feature when `--optional`
+/// is combined with one of these entries. That's manifest-writing logic that
+/// belongs in `cargo_add::ops::add`, which isn't part of this checkout, and
+/// nothing in this crate does it today.
 fn parse_feature(feature: &str) -> impl Iterator<Item = &str> {
     // Not re-using `CliFeatures` because it uses a BTreeSet and loses user's ordering
     feature
@@ -331,3 +399,75 @@ fn parse_feature(feature: &str) -> impl Iterator<Item = &str> {
         .flat_map(|s| s.split(','))
         .filter(|s| !s.is_empty())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> CargoResult<Vec<DepOp>> {
+        let config = Config::default().unwrap();
+        let matches = cli().no_binary_name(true).try_get_matches_from(args).unwrap();
+        parse_dependencies(&config, &matches)
+    }
+
+    #[test]
+    fn features_bind_to_the_preceding_crate() {
+        let deps = parse(&[
+            "serde",
+            "-F",
+            "derive",
+            "serde_json",
+            "-F",
+            "preserve_order",
+        ])
+        .unwrap();
+
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].crate_spec, "serde");
+        assert_eq!(
+            deps[0].features.as_ref().unwrap().iter().collect::<Vec<_>>(),
+            vec!["derive"],
+        );
+        assert_eq!(deps[1].crate_spec, "serde_json");
+        assert_eq!(
+            deps[1].features.as_ref().unwrap().iter().collect::<Vec<_>>(),
+            vec!["preserve_order"],
+        );
+    }
+
+    #[test]
+    fn features_before_any_crate_is_an_error() {
+        let err = parse(&["-F", "derive", "serde"]).unwrap_err();
+        assert!(
+            err.to_string().contains("must be preceded by a pkgid"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn path_with_multiple_crates_is_an_error() {
+        let err = parse(&["serde", "serde_json", "--path", "./local"]).unwrap_err();
+        assert!(
+            err.to_string().contains("cannot specify multiple crates with path"),
+            "unexpected error: {err}",
+        );
+    }
+
+    #[test]
+    fn path_conflicts_with_git() {
+        let err = cli()
+            .no_binary_name(true)
+            .try_get_matches_from(["serde", "--path", "./local", "--git", "https://example.com/serde"])
+            .unwrap_err();
+        assert_eq!(err.kind(), clap::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn path_conflicts_with_registry() {
+        let err = cli()
+            .no_binary_name(true)
+            .try_get_matches_from(["serde", "--path", "./local", "--registry", "my-registry"])
+            .unwrap_err();
+        assert_eq!(err.kind(), clap::ErrorKind::ArgumentConflict);
+    }
+}